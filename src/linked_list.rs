@@ -1,12 +1,28 @@
 use std::fmt::{self, Display, Formatter};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
 
 use thiserror::Error;
 
-type NodePointer<T> = Option<Box<Node<T>>>;
-
 struct Node<T> {
+    next: Option<NonNull<Node<T>>>,
+    prev: Option<NonNull<Node<T>>>,
     value: T,
-    next: NodePointer<T>,
+}
+
+impl<T> Node<T> {
+    fn new(value: T) -> Self {
+        Node {
+            next: None,
+            prev: None,
+            value,
+        }
+    }
+
+    fn into_value(self: Box<Self>) -> T {
+        self.value
+    }
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -16,58 +32,237 @@ pub enum LinkedListError {
 }
 
 pub struct LinkedList<T> {
-    head: NodePointer<T>,
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<Box<Node<T>>>,
 }
 
 impl<T> LinkedList<T> {
     pub fn new() -> Self {
-        LinkedList { head: None }
+        LinkedList {
+            head: None,
+            tail: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
     pub fn iter(&self) -> LinkedListIterator<T> {
         LinkedListIterator {
-            current: self.head.as_deref(),
+            current: self.head,
+            marker: PhantomData,
         }
     }
 
-    pub fn push(&mut self, value: T) {
-        let node = Box::new(Node {
-            value,
-            next: self.head.take(),
-        });
-        self.head = Some(node);
+    pub fn iter_mut(&mut self) -> LinkedListIterMut<T> {
+        LinkedListIterMut {
+            current: self.head,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn cursor(&self) -> Cursor<T> {
+        Cursor {
+            list: self,
+            current: None,
+        }
+    }
+
+    pub fn cursor_mut(&mut self) -> CursorMut<T> {
+        CursorMut {
+            list: self,
+            current: None,
+        }
     }
 
-    pub fn pop(&mut self) -> Option<T> {
-        self.head.take().map(|node| {
+    fn push_front_node(&mut self, mut node: Box<Node<T>>) {
+        unsafe {
+            node.next = self.head;
+            node.prev = None;
+            let node = Some(NonNull::from(Box::leak(node)));
+
+            match self.head {
+                None => self.tail = node,
+                Some(head) => (*head.as_ptr()).prev = node,
+            }
+
+            self.head = node;
+            self.len += 1;
+        }
+    }
+
+    fn pop_front_node(&mut self) -> Option<Box<Node<T>>> {
+        self.head.map(|node| unsafe {
+            let node = Box::from_raw(node.as_ptr());
             self.head = node.next;
-            node.value
+
+            match self.head {
+                None => self.tail = None,
+                Some(head) => (*head.as_ptr()).prev = None,
+            }
+
+            self.len -= 1;
+            node
         })
     }
 
+    fn push_back_node(&mut self, mut node: Box<Node<T>>) {
+        unsafe {
+            node.next = None;
+            node.prev = self.tail;
+            let node = Some(NonNull::from(Box::leak(node)));
+
+            match self.tail {
+                None => self.head = node,
+                Some(tail) => (*tail.as_ptr()).next = node,
+            }
+
+            self.tail = node;
+            self.len += 1;
+        }
+    }
+
+    fn pop_back_node(&mut self) -> Option<Box<Node<T>>> {
+        self.tail.map(|node| unsafe {
+            let node = Box::from_raw(node.as_ptr());
+            self.tail = node.prev;
+
+            match self.tail {
+                None => self.head = None,
+                Some(tail) => (*tail.as_ptr()).next = None,
+            }
+
+            self.len -= 1;
+            node
+        })
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        self.push_front_node(Box::new(Node::new(value)));
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.pop_front_node().map(Node::into_value)
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        self.push_back_node(Box::new(Node::new(value)));
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.pop_back_node().map(Node::into_value)
+    }
+
     pub fn insert(&mut self, index: usize, value: T) -> Result<(), LinkedListError> {
         if index == 0 {
-            self.push(value);
+            self.push_front(value);
+            return Ok(());
+        }
+        if index == self.len {
+            self.push_back(value);
             return Ok(());
         }
+        if index > self.len {
+            return Err(LinkedListError::OutOfBounds);
+        }
+
+        unsafe {
+            let mut current = self.head;
+            for _ in 0..index {
+                current = current.and_then(|node| (*node.as_ptr()).next);
+            }
+            let current = current.ok_or(LinkedListError::OutOfBounds)?;
+            let prev = (*current.as_ptr()).prev;
+
+            let mut node = Box::new(Node::new(value));
+            node.prev = prev;
+            node.next = Some(current);
+            let node = Some(NonNull::from(Box::leak(node)));
+
+            (*current.as_ptr()).prev = node;
+            match prev {
+                Some(prev) => (*prev.as_ptr()).next = node,
+                None => self.head = node,
+            }
+
+            self.len += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Splits the list into two at the given index, returning the tail as a
+    /// new list. This is O(n) to find the split point but O(1) to detach it,
+    /// since no elements are cloned or reallocated.
+    pub fn split_off(&mut self, at: usize) -> Result<LinkedList<T>, LinkedListError> {
+        if at > self.len {
+            return Err(LinkedListError::OutOfBounds);
+        }
+
+        if at == 0 {
+            return Ok(std::mem::replace(self, LinkedList::new()));
+        }
 
-        let mut current = &mut self.head;
-        let mut count = 0;
+        if at == self.len {
+            return Ok(LinkedList::new());
+        }
 
-        while let Some(node) = current {
-            if count + 1 == index {
-                let new_node = Box::new(Node {
-                    value,
-                    next: node.next.take(),
-                });
-                node.next = Some(new_node);
-                return Ok(());
+        unsafe {
+            let mut split_node = self.head;
+            for _ in 0..at {
+                split_node = split_node.and_then(|node| (*node.as_ptr()).next);
             }
-            count += 1;
-            current = &mut node.next;
+            let split_node = split_node.expect("at is within bounds");
+            let split_prev = (*split_node.as_ptr()).prev;
+
+            (*split_node.as_ptr()).prev = None;
+            let new_tail = self.tail;
+
+            self.tail = split_prev;
+            match split_prev {
+                Some(prev) => (*prev.as_ptr()).next = None,
+                None => self.head = None,
+            }
+
+            let new_len = self.len - at;
+            self.len = at;
+
+            Ok(LinkedList {
+                head: Some(split_node),
+                tail: new_tail,
+                len: new_len,
+                marker: PhantomData,
+            })
         }
+    }
+
+    /// Moves all of `other`'s nodes onto the end of `self` in O(1), leaving
+    /// `other` empty.
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        match self.tail {
+            None => std::mem::swap(self, other),
+            Some(tail) => {
+                if let Some(other_head) = other.head.take() {
+                    unsafe {
+                        (*tail.as_ptr()).next = Some(other_head);
+                        (*other_head.as_ptr()).prev = Some(tail);
+                    }
 
-        Err(LinkedListError::OutOfBounds)
+                    self.tail = other.tail.take();
+                    self.len += other.len;
+                    other.len = 0;
+                }
+            }
+        }
     }
 }
 
@@ -77,13 +272,16 @@ impl<T> Default for LinkedList<T> {
     }
 }
 
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front_node().is_some() {}
+    }
+}
+
 impl<T: Display> Display for LinkedList<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let mut current = &self.head;
-
-        while let Some(node) = current {
-            write!(f, "{} -> ", node.value)?;
-            current = &node.next;
+        for value in self.iter() {
+            write!(f, "{} -> ", value)?;
         }
 
         write!(f, "None")
@@ -93,67 +291,456 @@ impl<T: Display> Display for LinkedList<T> {
 impl<T> From<Vec<T>> for LinkedList<T> {
     fn from(vec: Vec<T>) -> Self {
         let mut list = LinkedList::new();
-        for value in vec.into_iter().rev() {
-            list.push(value);
+        for value in vec {
+            list.push_back(value);
         }
         list
     }
 }
 
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = LinkedListIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
 pub struct LinkedListIterator<'a, T> {
-    current: Option<&'a Node<T>>,
+    current: Option<NonNull<Node<T>>>,
+    marker: PhantomData<&'a Node<T>>,
 }
 
 impl<'a, T> Iterator for LinkedListIterator<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.current.take().map(|node| {
-            self.current = node.next.as_deref();
+        self.current.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.current = node.next;
             &node.value
         })
     }
 }
 
+pub struct LinkedListIterMut<'a, T> {
+    current: Option<NonNull<Node<T>>>,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for LinkedListIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current.map(|node| unsafe {
+            let node = &mut *node.as_ptr();
+            self.current = node.next;
+            &mut node.value
+        })
+    }
+}
+
+/// A cursor over a `LinkedList` that can read but not mutate the list.
+///
+/// A cursor is conceptually positioned either on an element, or on the
+/// "ghost" non-element that sits between the tail and the head. A freshly
+/// created cursor starts on the ghost element.
+pub struct Cursor<'a, T> {
+    list: &'a LinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => unsafe { self.current = (*node.as_ptr()).next },
+            None => self.current = self.list.head,
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => unsafe { self.current = (*node.as_ptr()).prev },
+            None => self.current = self.list.tail,
+        }
+    }
+
+    pub fn current(&self) -> Option<&T> {
+        unsafe { self.current.map(|node| &(*node.as_ptr()).value) }
+    }
+
+    pub fn peek_next(&self) -> Option<&T> {
+        unsafe {
+            let next = match self.current {
+                Some(node) => (*node.as_ptr()).next,
+                None => self.list.head,
+            };
+            next.map(|node| &(*node.as_ptr()).value)
+        }
+    }
+
+    pub fn peek_prev(&self) -> Option<&T> {
+        unsafe {
+            let prev = match self.current {
+                Some(node) => (*node.as_ptr()).prev,
+                None => self.list.tail,
+            };
+            prev.map(|node| &(*node.as_ptr()).value)
+        }
+    }
+}
+
+/// A cursor over a `LinkedList` that can read and mutate the list in place.
+///
+/// Like [`Cursor`], a `CursorMut` is positioned either on an element or on
+/// the "ghost" non-element between the tail and the head. Inserting next to
+/// the ghost element appends to whichever end of the list it borders.
+pub struct CursorMut<'a, T> {
+    list: &'a mut LinkedList<T>,
+    current: Option<NonNull<Node<T>>>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => unsafe { self.current = (*node.as_ptr()).next },
+            None => self.current = self.list.head,
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => unsafe { self.current = (*node.as_ptr()).prev },
+            None => self.current = self.list.tail,
+        }
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        unsafe { self.current.map(|node| &mut (*node.as_ptr()).value) }
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        unsafe {
+            let next = match self.current {
+                Some(node) => (*node.as_ptr()).next,
+                None => self.list.head,
+            };
+            next.map(|node| &mut (*node.as_ptr()).value)
+        }
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        unsafe {
+            let prev = match self.current {
+                Some(node) => (*node.as_ptr()).prev,
+                None => self.list.tail,
+            };
+            prev.map(|node| &mut (*node.as_ptr()).value)
+        }
+    }
+
+    /// Inserts `value` just after the current element, without moving the
+    /// cursor. If the cursor is on the ghost element, the value becomes the
+    /// new head of the list.
+    pub fn insert_after(&mut self, value: T) {
+        match self.current {
+            None => self.list.push_front_node(Box::new(Node::new(value))),
+            Some(node) => unsafe {
+                let next = (*node.as_ptr()).next;
+                let mut new_node = Box::new(Node::new(value));
+                new_node.prev = Some(node);
+                new_node.next = next;
+                let new_node = Some(NonNull::from(Box::leak(new_node)));
+
+                (*node.as_ptr()).next = new_node;
+                match next {
+                    Some(next) => (*next.as_ptr()).prev = new_node,
+                    None => self.list.tail = new_node,
+                }
+
+                self.list.len += 1;
+            },
+        }
+    }
+
+    /// Inserts `value` just before the current element, without moving the
+    /// cursor. If the cursor is on the ghost element, the value becomes the
+    /// new tail of the list.
+    pub fn insert_before(&mut self, value: T) {
+        match self.current {
+            None => self.list.push_back_node(Box::new(Node::new(value))),
+            Some(node) => unsafe {
+                let prev = (*node.as_ptr()).prev;
+                let mut new_node = Box::new(Node::new(value));
+                new_node.next = Some(node);
+                new_node.prev = prev;
+                let new_node = Some(NonNull::from(Box::leak(new_node)));
+
+                (*node.as_ptr()).prev = new_node;
+                match prev {
+                    Some(prev) => (*prev.as_ptr()).next = new_node,
+                    None => self.list.head = new_node,
+                }
+
+                self.list.len += 1;
+            },
+        }
+    }
+
+    /// Removes the current element, leaving the cursor on the element that
+    /// followed it (or the ghost element, if there was none).
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+
+        unsafe {
+            let next = (*node.as_ptr()).next;
+            let prev = (*node.as_ptr()).prev;
+
+            match prev {
+                Some(prev) => (*prev.as_ptr()).next = next,
+                None => self.list.head = next,
+            }
+            match next {
+                Some(next) => (*next.as_ptr()).prev = prev,
+                None => self.list.tail = prev,
+            }
+
+            self.list.len -= 1;
+            self.current = next;
+
+            Some(Box::from_raw(node.as_ptr()).into_value())
+        }
+    }
+
+    /// Splices the entire `other` list in just after the current element,
+    /// leaving `other` empty. If the cursor is on the ghost element, `other`
+    /// is spliced in at the front of the list.
+    pub fn splice_after(&mut self, mut other: LinkedList<T>) {
+        if other.is_empty() {
+            return;
+        }
+
+        let other_head = other.head.take().unwrap();
+        let other_tail = other.tail.take().unwrap();
+        let other_len = other.len;
+        other.len = 0;
+
+        unsafe {
+            match self.current {
+                None => {
+                    match self.list.head {
+                        Some(head) => {
+                            (*other_tail.as_ptr()).next = Some(head);
+                            (*head.as_ptr()).prev = Some(other_tail);
+                        }
+                        None => self.list.tail = Some(other_tail),
+                    }
+                    self.list.head = Some(other_head);
+                }
+                Some(node) => {
+                    let next = (*node.as_ptr()).next;
+                    (*node.as_ptr()).next = Some(other_head);
+                    (*other_head.as_ptr()).prev = Some(node);
+
+                    match next {
+                        Some(next) => {
+                            (*other_tail.as_ptr()).next = Some(next);
+                            (*next.as_ptr()).prev = Some(other_tail);
+                        }
+                        None => self.list.tail = Some(other_tail),
+                    }
+                }
+            }
+
+            self.list.len += other_len;
+        }
+    }
+
+    /// Splices the entire `other` list in just before the current element,
+    /// leaving `other` empty. If the cursor is on the ghost element, `other`
+    /// is spliced in at the back of the list.
+    pub fn splice_before(&mut self, mut other: LinkedList<T>) {
+        if other.is_empty() {
+            return;
+        }
+
+        let other_head = other.head.take().unwrap();
+        let other_tail = other.tail.take().unwrap();
+        let other_len = other.len;
+        other.len = 0;
+
+        unsafe {
+            match self.current {
+                None => {
+                    match self.list.tail {
+                        Some(tail) => {
+                            (*other_head.as_ptr()).prev = Some(tail);
+                            (*tail.as_ptr()).next = Some(other_head);
+                        }
+                        None => self.list.head = Some(other_head),
+                    }
+                    self.list.tail = Some(other_tail);
+                }
+                Some(node) => {
+                    let prev = (*node.as_ptr()).prev;
+                    (*node.as_ptr()).prev = Some(other_tail);
+                    (*other_tail.as_ptr()).next = Some(node);
+
+                    match prev {
+                        Some(prev) => {
+                            (*other_head.as_ptr()).prev = Some(prev);
+                            (*prev.as_ptr()).next = Some(other_head);
+                        }
+                        None => self.list.head = Some(other_head),
+                    }
+                }
+            }
+
+            self.list.len += other_len;
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
     use super::*;
 
     #[test]
     fn empty() {
         let list: LinkedList<i32> = LinkedList::new();
         assert_eq!(list.to_string(), "None");
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
     }
 
     #[test]
-    fn push() {
+    fn push_front() {
         let mut list: LinkedList<i32> = LinkedList::new();
-        list.push(1);
-        list.push(2);
-        list.push(3);
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
         assert_eq!(list.to_string(), "3 -> 2 -> 1 -> None");
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn push_back() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn mixed_front_and_back() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.push_back(2);
+        list.push_front(1);
+        list.push_back(3);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
     }
 
     #[test]
-    fn pop() {
+    fn pop_front() {
         let mut list: LinkedList<i32> = LinkedList::new();
-        list.push(1);
-        list.push(2);
-        assert_eq!(list.pop(), Some(2));
+        list.push_front(1);
+        list.push_front(2);
+        assert_eq!(list.pop_front(), Some(2));
         assert_eq!(list.to_string(), "1 -> None");
     }
 
     #[test]
-    fn pop_empty() {
+    fn pop_front_empty() {
         let mut list: LinkedList<i32> = LinkedList::new();
-        assert_eq!(list.pop(), None);
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn pop_back() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.to_string(), "1 -> None");
+    }
+
+    #[test]
+    fn pop_back_empty() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        assert_eq!(list.pop_back(), None);
+    }
+
+    #[test]
+    fn pop_back_to_empty_resets_head() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        list.push_back(1);
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
     }
 
     #[test]
     fn insert_at_head() {
         let mut list: LinkedList<i32> = LinkedList::new();
-        list.push(1);
-        list.push(2);
+        list.push_front(1);
+        list.push_front(2);
         list.insert(0, 3).unwrap();
         assert_eq!(list.to_string(), "3 -> 2 -> 1 -> None");
     }
@@ -161,8 +748,8 @@ mod test {
     #[test]
     fn insert_in_middle() {
         let mut list: LinkedList<i32> = LinkedList::new();
-        list.push(1);
-        list.push(3);
+        list.push_front(1);
+        list.push_front(3);
         list.insert(1, 2).unwrap();
         assert_eq!(list.to_string(), "3 -> 2 -> 1 -> None");
     }
@@ -170,7 +757,7 @@ mod test {
     #[test]
     fn insert_out_of_bounds() {
         let mut list: LinkedList<i32> = LinkedList::new();
-        list.push(1);
+        list.push_front(1);
         let result = list.insert(10, 2);
         assert_eq!(result, Err(LinkedListError::OutOfBounds));
     }
@@ -178,8 +765,8 @@ mod test {
     #[test]
     fn insert_at_tail() {
         let mut list: LinkedList<i32> = LinkedList::new();
-        list.push(2);
-        list.push(1);
+        list.push_front(2);
+        list.push_front(1);
         list.insert(2, 3).unwrap();
         assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
     }
@@ -194,7 +781,7 @@ mod test {
     #[test]
     fn iter_single_element() {
         let mut list: LinkedList<i32> = LinkedList::new();
-        list.push(1);
+        list.push_front(1);
         let mut iter = list.iter();
         assert_eq!(iter.next(), Some(&1));
         assert_eq!(iter.next(), None);
@@ -203,9 +790,9 @@ mod test {
     #[test]
     fn iter_multiple_elements() {
         let mut list: LinkedList<i32> = LinkedList::new();
-        list.push(1);
-        list.push(2);
-        list.push(3);
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
         let mut iter = list.iter();
         assert_eq!(iter.next(), Some(&3));
         assert_eq!(iter.next(), Some(&2));
@@ -216,9 +803,9 @@ mod test {
     #[test]
     fn iter_does_not_consume_list() {
         let mut list: LinkedList<i32> = LinkedList::new();
-        list.push(1);
-        list.push(2);
-        list.push(3);
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
         let mut iter = list.iter();
         assert_eq!(iter.next(), Some(&3));
         assert_eq!(iter.next(), Some(&2));
@@ -232,4 +819,275 @@ mod test {
 
         assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> None");
     }
+
+    #[test]
+    fn cursor_mut_starts_on_ghost() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3]);
+        let mut cursor = list.cursor_mut();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&mut 1));
+        assert_eq!(cursor.peek_prev(), Some(&mut 3));
+    }
+
+    #[test]
+    fn cursor_mut_navigates_forward_and_back() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3]);
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&mut 1));
+    }
+
+    #[test]
+    fn cursor_mut_edit_in_place() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3]);
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        cursor.move_next();
+        *cursor.current().unwrap() *= 10;
+        assert_eq!(list.to_string(), "1 -> 20 -> 3 -> None");
+    }
+
+    #[test]
+    fn cursor_mut_insert_after_ghost_is_push_front() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![2, 3]);
+        let mut cursor = list.cursor_mut();
+        cursor.insert_after(1);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+    }
+
+    #[test]
+    fn cursor_mut_insert_before_ghost_is_push_back() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2]);
+        let mut cursor = list.cursor_mut();
+        cursor.insert_before(3);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+    }
+
+    #[test]
+    fn cursor_mut_insert_after_current() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 3]);
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        cursor.insert_after(2);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+    }
+
+    #[test]
+    fn cursor_mut_insert_before_current() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 3]);
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        cursor.insert_before(0);
+        assert_eq!(list.to_string(), "0 -> 1 -> 3 -> None");
+    }
+
+    #[test]
+    fn cursor_mut_remove_current_moves_to_next() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3]);
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 3));
+        assert_eq!(list.to_string(), "1 -> 3 -> None");
+    }
+
+    #[test]
+    fn cursor_mut_remove_current_on_ghost_is_noop() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1]);
+        let mut cursor = list.cursor_mut();
+        assert_eq!(cursor.remove_current(), None);
+    }
+
+    #[test]
+    fn cursor_read_only_navigation() {
+        let list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3]);
+        let mut cursor = list.cursor();
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&1));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&3));
+    }
+
+    #[test]
+    fn from_iterator() {
+        let list: LinkedList<i32> = (0..5).collect();
+        assert_eq!(list.to_string(), "0 -> 1 -> 2 -> 3 -> 4 -> None");
+    }
+
+    #[test]
+    fn extend() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2]);
+        list.extend(vec![3, 4]);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> None");
+    }
+
+    #[test]
+    fn into_iter_by_value() {
+        let list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3]);
+        let collected: Vec<i32> = list.into_iter().collect();
+        assert_eq!(collected, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn into_iter_double_ended() {
+        let list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3, 4]);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn into_iter_exact_size() {
+        let list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3]);
+        let iter = list.into_iter();
+        assert_eq!(iter.len(), 3);
+    }
+
+    #[test]
+    fn borrowed_into_iterator() {
+        let list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3]);
+        let collected: Vec<&i32> = (&list).into_iter().collect();
+        assert_eq!(collected, vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn iter_mut_edits_in_place() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3]);
+        for x in list.iter_mut() {
+            *x *= 2;
+        }
+        assert_eq!(list.to_string(), "2 -> 4 -> 6 -> None");
+    }
+
+    #[test]
+    fn iter_mut_empty_list() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn split_off_in_middle() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3, 4]);
+        let tail = list.split_off(2).unwrap();
+        assert_eq!(list.to_string(), "1 -> 2 -> None");
+        assert_eq!(tail.to_string(), "3 -> 4 -> None");
+        assert_eq!(list.len(), 2);
+        assert_eq!(tail.len(), 2);
+    }
+
+    #[test]
+    fn split_off_at_zero_moves_everything() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3]);
+        let tail = list.split_off(0).unwrap();
+        assert!(list.is_empty());
+        assert_eq!(tail.to_string(), "1 -> 2 -> 3 -> None");
+    }
+
+    #[test]
+    fn split_off_at_len_returns_empty() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2, 3]);
+        let tail = list.split_off(3).unwrap();
+        assert!(tail.is_empty());
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> None");
+    }
+
+    #[test]
+    fn split_off_out_of_bounds() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2]);
+        assert!(matches!(
+            list.split_off(5),
+            Err(LinkedListError::OutOfBounds)
+        ));
+    }
+
+    #[test]
+    fn append_joins_lists_and_empties_other() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2]);
+        let mut other: LinkedList<i32> = LinkedList::from(vec![3, 4]);
+        list.append(&mut other);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> None");
+        assert!(other.is_empty());
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn append_to_empty_list() {
+        let mut list: LinkedList<i32> = LinkedList::new();
+        let mut other: LinkedList<i32> = LinkedList::from(vec![1, 2]);
+        list.append(&mut other);
+        assert_eq!(list.to_string(), "1 -> 2 -> None");
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn cursor_mut_splice_after_current() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 4]);
+        let donor: LinkedList<i32> = LinkedList::from(vec![2, 3]);
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        cursor.splice_after(donor);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> None");
+        assert_eq!(list.len(), 4);
+    }
+
+    #[test]
+    fn cursor_mut_splice_before_current() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 4]);
+        let donor: LinkedList<i32> = LinkedList::from(vec![2, 3]);
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        cursor.move_next();
+        cursor.splice_before(donor);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> None");
+    }
+
+    #[test]
+    fn cursor_mut_splice_after_ghost_is_front() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![3, 4]);
+        let donor: LinkedList<i32> = LinkedList::from(vec![1, 2]);
+        let mut cursor = list.cursor_mut();
+        cursor.splice_after(donor);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> None");
+    }
+
+    #[test]
+    fn cursor_mut_splice_before_ghost_is_back() {
+        let mut list: LinkedList<i32> = LinkedList::from(vec![1, 2]);
+        let donor: LinkedList<i32> = LinkedList::from(vec![3, 4]);
+        let mut cursor = list.cursor_mut();
+        cursor.splice_before(donor);
+        assert_eq!(list.to_string(), "1 -> 2 -> 3 -> 4 -> None");
+    }
+
+    #[test]
+    fn drop_does_not_leak() {
+        struct DropCounter(Rc<Cell<usize>>);
+
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let count = Rc::new(Cell::new(0));
+        let mut list = LinkedList::new();
+        for _ in 0..5 {
+            list.push_back(DropCounter(count.clone()));
+        }
+        drop(list);
+
+        assert_eq!(count.get(), 5);
+    }
 }